@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use bevy_app::{Plugin, Startup, Update};
 use bevy_asset::AssetServer;
 use bevy_color::Color;
@@ -6,11 +8,17 @@ use bevy_ecs::{
     change_detection::DetectChanges,
     component::Component,
     query::With,
-    system::{Commands, Query, Res, Resource},
+    system::{Commands, Query, Res, ResMut, Resource},
 };
+use bevy_input::{keyboard::KeyCode, ButtonInput};
+use bevy_reflect::Reflect;
+use bevy_render::view::Visibility;
 use bevy_text::{Text, TextSection, TextStyle};
+use bevy_time::{Time, Timer, TimerMode};
 use bevy_ui::node_bundles::TextBundle;
 
+use crate::{DevTool, DevToolsApp};
+
 #[derive(Default)]
 pub struct FpsOverlayPlugin {
     pub config: FpsOverlayConfig,
@@ -22,8 +30,21 @@ impl Plugin for FpsOverlayPlugin {
             app.add_plugins(FrameTimeDiagnosticsPlugin);
         }
         app.insert_resource(self.config.clone())
+            .insert_resource(FpsUpdateTimer(Timer::new(
+                self.config.update_interval,
+                TimerMode::Repeating,
+            )))
+            .init_dev_tool::<FpsOverlay>()
             .add_systems(Startup, setup)
-            .add_systems(Update, (customize_text, update_text));
+            .add_systems(
+                Update,
+                (
+                    customize_text,
+                    update_text,
+                    toggle_display,
+                    toggle_overlay_key,
+                ),
+            );
     }
 }
 
@@ -32,6 +53,22 @@ pub struct FpsOverlayConfig {
     pub font_path: Option<String>,
     pub font_size: f32,
     pub font_color: Color,
+    /// How often the overlay's text is refreshed.
+    ///
+    /// Defaults to once every 0.25 seconds so the displayed value doesn't flicker
+    /// too fast to read.
+    pub update_interval: Duration,
+    /// Whether to also display the average frame time, in milliseconds.
+    pub show_frame_time: bool,
+    /// Whether to also display the min/max FPS observed over the diagnostic's history buffer.
+    ///
+    /// A smoothed FPS value can hide spikes that this surfaces, which is useful for
+    /// tracking down stutter.
+    pub show_min_max: bool,
+    /// Key used to toggle the overlay on and off at runtime.
+    ///
+    /// Defaults to `None`, which disables this behavior.
+    pub toggle_key: Option<KeyCode>,
 }
 
 impl Default for FpsOverlayConfig {
@@ -40,37 +77,56 @@ impl Default for FpsOverlayConfig {
             font_path: None,
             font_size: 32.0,
             font_color: Color::WHITE,
+            update_interval: Duration::from_secs_f32(0.25),
+            show_frame_time: false,
+            show_min_max: false,
+            toggle_key: None,
         }
     }
 }
 
+/// Drives how often [`update_text`] reformats the overlay's text.
+#[derive(Resource)]
+struct FpsUpdateTimer(Timer);
+
+/// A [`DevTool`] that lets the FPS overlay be enabled or disabled through
+/// [`Enable<FpsOverlay>`](crate::Enable), [`Disable<FpsOverlay>`](crate::Disable) and
+/// [`Toggle<FpsOverlay>`](crate::Toggle) commands.
+#[derive(Resource, Reflect, Debug)]
+#[reflect(DevTool)]
+pub struct FpsOverlay {
+    pub enabled: bool,
+}
+
+impl Default for FpsOverlay {
+    fn default() -> Self {
+        FpsOverlay { enabled: true }
+    }
+}
+
+impl DevTool for FpsOverlay {
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+}
+
 #[derive(Component)]
 struct FpsText;
 
 fn setup(
     mut commands: Commands,
     overlay_config: Res<FpsOverlayConfig>,
+    overlay: Res<FpsOverlay>,
     asset_server: Res<AssetServer>,
 ) {
-    commands.spawn((
-        TextBundle::from_sections([
-            TextSection::new(
-                "FPS: ",
-                if let Some(font_path) = &overlay_config.font_path {
-                    TextStyle {
-                        font_size: overlay_config.font_size,
-                        color: overlay_config.font_color,
-                        font: asset_server.load(font_path),
-                    }
-                } else {
-                    TextStyle {
-                        font_size: overlay_config.font_size,
-                        color: overlay_config.font_color,
-                        ..Default::default()
-                    }
-                },
-            ),
-            TextSection::from_style(if let Some(font_path) = &overlay_config.font_path {
+    let mut text_bundle = TextBundle::from_sections([
+        TextSection::new(
+            "FPS: ",
+            if let Some(font_path) = &overlay_config.font_path {
                 TextStyle {
                     font_size: overlay_config.font_size,
                     color: overlay_config.font_color,
@@ -82,18 +138,87 @@ fn setup(
                     color: overlay_config.font_color,
                     ..Default::default()
                 }
-            }),
-        ]),
-        FpsText,
-    ));
+            },
+        ),
+        TextSection::from_style(if let Some(font_path) = &overlay_config.font_path {
+            TextStyle {
+                font_size: overlay_config.font_size,
+                color: overlay_config.font_color,
+                font: asset_server.load(font_path),
+            }
+        } else {
+            TextStyle {
+                font_size: overlay_config.font_size,
+                color: overlay_config.font_color,
+                ..Default::default()
+            }
+        }),
+        TextSection::from_style(if let Some(font_path) = &overlay_config.font_path {
+            TextStyle {
+                font_size: overlay_config.font_size,
+                color: overlay_config.font_color,
+                font: asset_server.load(font_path),
+            }
+        } else {
+            TextStyle {
+                font_size: overlay_config.font_size,
+                color: overlay_config.font_color,
+                ..Default::default()
+            }
+        }),
+    ]);
+
+    text_bundle.visibility = if overlay.enabled {
+        Visibility::Visible
+    } else {
+        Visibility::Hidden
+    };
+
+    commands.spawn((text_bundle, FpsText));
 }
 
-fn update_text(diagnostic: Res<DiagnosticsStore>, mut query: Query<&mut Text, With<FpsText>>) {
+fn update_text(
+    diagnostic: Res<DiagnosticsStore>,
+    overlay_config: Res<FpsOverlayConfig>,
+    mut query: Query<&mut Text, With<FpsText>>,
+    time: Res<Time>,
+    mut timer: ResMut<FpsUpdateTimer>,
+) {
+    if !timer.0.tick(time.delta()).just_finished() {
+        return;
+    }
+
     for mut text in &mut query {
         if let Some(fps) = diagnostic.get(&FrameTimeDiagnosticsPlugin::FPS) {
             if let Some(value) = fps.smoothed() {
                 text.sections[1].value = format!("{value:.2}");
             }
+
+            let mut extra = String::new();
+
+            if overlay_config.show_min_max {
+                let (min, max) = fps
+                    .values()
+                    .fold(None, |acc: Option<(f64, f64)>, &value| {
+                        Some(acc.map_or((value, value), |(min, max)| {
+                            (min.min(value), max.max(value))
+                        }))
+                    })
+                    .unwrap_or((0.0, 0.0));
+
+                extra.push_str(&format!(" (min {min:.0} / max {max:.0})"));
+            }
+
+            if overlay_config.show_frame_time {
+                let frame_time = diagnostic
+                    .get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)
+                    .and_then(|frame_time| frame_time.average())
+                    .unwrap_or(0.0);
+
+                extra.push_str(&format!("  frame: {frame_time:.1}ms"));
+            }
+
+            text.sections[2].value = extra;
         }
     }
 }
@@ -102,11 +227,15 @@ fn customize_text(
     overlay_config: Res<FpsOverlayConfig>,
     asset_server: Res<AssetServer>,
     mut query: Query<&mut Text, With<FpsText>>,
+    mut timer: ResMut<FpsUpdateTimer>,
 ) {
     if !overlay_config.is_changed() {
         return;
     }
 
+    timer.0.set_duration(overlay_config.update_interval);
+    timer.0.reset();
+
     for mut text in &mut query {
         for section in text.sections.iter_mut() {
             section.style = if let Some(font_path) = &overlay_config.font_path {
@@ -125,3 +254,32 @@ fn customize_text(
         }
     }
 }
+
+fn toggle_overlay_key(
+    overlay_config: Res<FpsOverlayConfig>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut overlay: ResMut<FpsOverlay>,
+) {
+    let Some(toggle_key) = overlay_config.toggle_key else {
+        return;
+    };
+
+    if keyboard_input.just_pressed(toggle_key) {
+        let enabled = overlay.enabled;
+        overlay.set_enabled(!enabled);
+    }
+}
+
+fn toggle_display(overlay: Res<FpsOverlay>, mut query: Query<&mut Visibility, With<FpsText>>) {
+    if !overlay.is_changed() {
+        return;
+    }
+
+    for mut visibility in &mut query {
+        *visibility = if overlay.enabled {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
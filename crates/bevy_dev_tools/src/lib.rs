@@ -153,8 +153,10 @@ pub struct Enable<T: DevTool + GetTypeRegistration + Default> {
 }
 
 impl<T: DevTool + GetTypeRegistration + Default> Command for Enable<T> {
-    fn apply(mut self, _world: &mut World) {
-        self.dev_tool.set_enabled(true);
+    fn apply(self, world: &mut World) {
+        if let Some(mut dev_tool) = world.get_resource_mut::<T>() {
+            dev_tool.set_enabled(true);
+        }
     }
 }
 impl<T: DevTool + Default + TypePath + GetTypeRegistration + FromReflect> DevCommand for Enable<T> {}
@@ -166,8 +168,10 @@ pub struct Disable<T: DevTool + GetTypeRegistration + Default> {
 }
 
 impl<T: DevTool + GetTypeRegistration + Default> Command for Disable<T> {
-    fn apply(mut self, _world: &mut World) {
-        self.dev_tool.set_enabled(false);
+    fn apply(self, world: &mut World) {
+        if let Some(mut dev_tool) = world.get_resource_mut::<T>() {
+            dev_tool.set_enabled(false);
+        }
     }
 }
 impl<T: DevTool + Default + GetTypeRegistration + FromReflect + TypePath> DevCommand
@@ -182,8 +186,11 @@ pub struct Toggle<T: DevTool + GetTypeRegistration + Default> {
 }
 
 impl<T: DevTool + GetTypeRegistration + Default> Command for Toggle<T> {
-    fn apply(mut self, _world: &mut World) {
-        self.dev_tool.set_enabled(!self.dev_tool.is_enabled());
+    fn apply(self, world: &mut World) {
+        if let Some(mut dev_tool) = world.get_resource_mut::<T>() {
+            let enabled = dev_tool.is_enabled();
+            dev_tool.set_enabled(!enabled);
+        }
     }
 }
 impl<T: DevTool + Default + GetTypeRegistration + FromReflect + TypePath> DevCommand for Toggle<T> {}